@@ -0,0 +1,318 @@
+//! Divide-and-conquer Delaunay triangulation built on top of [`Manifold`],
+//! following the algorithm from Guibas & Stolfi (1985).
+
+use crate::{Manifold, Node};
+
+/// The orientation determinant `(b-a) x (c-a)`, whose sign is positive when
+/// `a, b, c` turn left (counter-clockwise), negative when they turn right,
+/// zero when collinear.
+pub fn ccw(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> f64 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+/// The 4x4 determinant testing whether `d` lies inside the circle
+/// through `a, b, c` (given in counter-clockwise order). A positive value
+/// means `d` is inside.
+pub fn in_circle(a: (f64, f64), b: (f64, f64), c: (f64, f64), d: (f64, f64)) -> f64 {
+    let row = |p: (f64, f64)| [p.0, p.1, p.0 * p.0 + p.1 * p.1, 1.0];
+    let m = [row(a), row(b), row(c), row(d)];
+    det4(m)
+}
+
+fn det3(m: [[f64; 3]; 3]) -> f64 {
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+fn det4(m: [[f64; 4]; 4]) -> f64 {
+    let minor = |skip_col: usize| {
+        let mut rows = [[0.0; 3]; 3];
+        for r in 0..3 {
+            let mut c = 0;
+            for (col, &val) in m[r + 1].iter().enumerate() {
+                if col == skip_col {
+                    continue;
+                }
+                rows[r][c] = val;
+                c += 1;
+            }
+        }
+        det3(rows)
+    };
+    m[0][0] * minor(0) - m[0][1] * minor(1) + m[0][2] * minor(2) - m[0][3] * minor(3)
+}
+
+/// The site attached to `n`'s origin.
+fn org<F: Copy>(n: Node<'_, (f64, f64), F>) -> (f64, f64) {
+    *n.org_data().expect("site-bearing node")
+}
+
+/// The site attached to `n`'s destination.
+fn dest<F: Copy>(n: Node<'_, (f64, f64), F>) -> (f64, f64) {
+    org(n.sym())
+}
+
+/// A point-bearing node in the triangulation under construction.
+type Site<'m, F> = Node<'m, (f64, f64), F>;
+
+/// A hull's `(leftmost, rightmost)` boundary edges, as returned by [`delaunay_rec`](Manifold::delaunay_rec).
+type Hull<'m, F> = (Site<'m, F>, Site<'m, F>);
+
+impl<'m, F: Default + Copy> Manifold<'m, (f64, f64), F> {
+    /// Builds the Delaunay triangulation of `points`, returning an edge on
+    /// the convex hull with the leftmost site as its origin, or `None` if
+    /// fewer than two distinct sites are given.
+    pub fn triangulate(&'m self, points: &[(f64, f64)]) -> Option<Site<'m, F>> {
+        let mut sites = points.to_vec();
+        sites.sort_by(|a, b| a.partial_cmp(b).expect("NaN coordinate"));
+        sites.dedup();
+        if sites.len() < 2 {
+            return None;
+        }
+        Some(self.delaunay_rec(&sites).0)
+    }
+
+    /// Like [`Manifold::connect`], but also copies the site data onto the
+    /// new edge's endpoints, since `set_org_data` only reaches the edges
+    /// already in a vertex ring and the new edge joins its rings later
+    /// (see the `org_data`/`set_org_data` docs).
+    fn connect_sites(&'m self, a: Site<'m, F>, b: Site<'m, F>) -> Site<'m, F> {
+        let e = self.connect(a, b);
+        self.set_org_data(e, dest(a));
+        self.set_org_data(e.sym(), org(b));
+        e
+    }
+
+    /// Triangulates `sites` (sorted, deduped, len >= 2) and returns
+    /// `(leftmost_edge, rightmost_edge)` on the resulting hull.
+    fn delaunay_rec(&'m self, sites: &[(f64, f64)]) -> Hull<'m, F> {
+        match sites.len() {
+            2 => {
+                let a = self.make_edge();
+                self.set_org_data(a, sites[0]);
+                self.set_org_data(a.sym(), sites[1]);
+                (a, a.sym())
+            }
+            3 => {
+                let a = self.make_edge();
+                let b = self.make_edge();
+                a.sym().splice(b);
+                self.set_org_data(a, sites[0]);
+                self.set_org_data(a.sym(), sites[1]);
+                self.set_org_data(b.sym(), sites[2]);
+                match ccw(sites[0], sites[1], sites[2]) {
+                    s if s > 0.0 => {
+                        self.connect_sites(b, a);
+                        (a, b.sym())
+                    }
+                    s if s < 0.0 => {
+                        let c = self.connect_sites(b, a);
+                        (c.sym(), c)
+                    }
+                    _ => (a, b.sym()),
+                }
+            }
+            n => {
+                let (left, right) = sites.split_at(n / 2);
+                let (ldo, ldi) = self.delaunay_rec(left);
+                let (rdi, rdo) = self.delaunay_rec(right);
+                self.merge(ldo, ldi, rdi, rdo)
+            }
+        }
+    }
+
+    /// Merges two adjacent Delaunay triangulations whose hulls are
+    /// described by `(ldo, ldi)` (left outer/inner) and `(rdi, rdo)`
+    /// (right inner/outer), returning the new `(outer, outer)` hull pair.
+    fn merge(
+        &'m self,
+        mut ldo: Site<'m, F>,
+        mut ldi: Site<'m, F>,
+        mut rdi: Site<'m, F>,
+        mut rdo: Site<'m, F>,
+    ) -> Hull<'m, F> {
+        // Find the lower common tangent of the two hulls.
+        loop {
+            if ccw(org(ldi), dest(ldi), org(rdi)) > 0.0 {
+                ldi = ldi.lnext();
+            } else if ccw(org(rdi), dest(rdi), org(ldi)) < 0.0 {
+                rdi = rdi.rprev();
+            } else {
+                break;
+            }
+        }
+
+        let mut base = self.connect_sites(rdi.sym(), ldi);
+        if org(ldi) == org(ldo) {
+            ldo = base.sym();
+        }
+        if org(rdi) == org(rdo) {
+            rdo = base;
+        }
+
+        // Zip the two hulls together, merging up from the base edge.
+        loop {
+            let mut lcand = base.sym().onext();
+            if ccw(org(base.sym()), org(base), dest(lcand)) > 0.0 {
+                let mut next = lcand.onext();
+                while ccw(org(base.sym()), org(base), dest(next)) > 0.0
+                    && in_circle(org(base.sym()), org(base), dest(lcand), dest(next)) > 0.0
+                {
+                    lcand.delete_edge();
+                    lcand = next;
+                    next = lcand.onext();
+                }
+            }
+
+            let mut rcand = base.oprev();
+            if ccw(org(base.sym()), org(base), dest(rcand)) > 0.0 {
+                let mut next = rcand.oprev();
+                while ccw(org(base.sym()), org(base), dest(next)) > 0.0
+                    && in_circle(org(base.sym()), org(base), dest(rcand), dest(next)) > 0.0
+                {
+                    rcand.delete_edge();
+                    rcand = next;
+                    next = rcand.oprev();
+                }
+            }
+
+            let valid_left = ccw(org(base.sym()), org(base), dest(lcand)) > 0.0;
+            let valid_right = ccw(org(base.sym()), org(base), dest(rcand)) > 0.0;
+            if !valid_left && !valid_right {
+                break;
+            }
+            if !valid_left
+                || (valid_right && in_circle(dest(lcand), org(lcand), org(rcand), dest(rcand)) > 0.0)
+            {
+                base = self.connect_sites(rcand, base.sym());
+            } else {
+                base = self.connect_sites(base.sym(), lcand.sym());
+            }
+        }
+
+        (ldo, rdo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    // The triangulation tests below use `std::collections::HashSet` purely
+    // as a scratch set for counting edges; bring `std` back in under
+    // `no_std` builds so they still compile and run there.
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    #[test]
+    fn check_ccw_sign() {
+        assert!(ccw((0.0, 0.0), (1.0, 0.0), (0.0, 1.0)) > 0.0);
+        assert!(ccw((0.0, 0.0), (0.0, 1.0), (1.0, 0.0)) < 0.0);
+        assert_eq!(ccw((0.0, 0.0), (1.0, 0.0), (2.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn check_in_circle_sign() {
+        let (a, b, c) = ((0.0, 0.0), (1.0, 0.0), (0.0, 1.0));
+        assert!(in_circle(a, b, c, (0.1, 0.1)) > 0.0);
+        assert!(in_circle(a, b, c, (5.0, 5.0)) < 0.0);
+    }
+
+    #[test]
+    fn check_triangulate_empty_and_single() {
+        let m: Manifold<(f64, f64), ()> = Manifold::default();
+        assert!(m.triangulate(&[]).is_none());
+        assert!(m.triangulate(&[(0.0, 0.0)]).is_none());
+    }
+
+    #[test]
+    fn check_triangulate_two_points() {
+        let m: Manifold<(f64, f64), ()> = Manifold::default();
+        let e = m.triangulate(&[(0.0, 0.0), (1.0, 0.0)]).unwrap();
+        assert_eq!(*e.org_data().unwrap(), (0.0, 0.0));
+        assert_eq!(*e.sym().org_data().unwrap(), (1.0, 0.0));
+    }
+
+    #[test]
+    fn check_triangulate_triangle() {
+        let m: Manifold<(f64, f64), ()> = Manifold::default();
+        let e = m
+            .triangulate(&[(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)])
+            .unwrap();
+        assert_eq!(e.face_ring().count(), 3);
+        assert_eq!(e.vertex_ring().count(), 2);
+    }
+
+    #[test]
+    fn check_triangulate_square_euler_formula() {
+        // A unit square: 4 sites, 4 hull edges. Euler's formula for a
+        // triangulated point set gives 2n - h - 2 triangles and
+        // 3n - h - 3 edges, where h is the number of hull vertices.
+        let m: Manifold<(f64, f64), ()> = Manifold::default();
+        let pts = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+        let e = m.triangulate(&pts).unwrap();
+
+        let mut seen = std::collections::HashSet::new();
+        let mut stack = vec![e];
+        let key = |n: Node<'_, (f64, f64), ()>| {
+            let (ox, oy) = *n.org_data().unwrap();
+            let (dx, dy) = *n.sym().org_data().unwrap();
+            (ox.to_bits(), oy.to_bits(), dx.to_bits(), dy.to_bits())
+        };
+        while let Some(n) = stack.pop() {
+            if !seen.insert(key(n)) {
+                continue;
+            }
+            stack.push(n.onext());
+            stack.push(n.oprev());
+            stack.push(n.sym());
+        }
+        let edge_count = seen.len() / 2;
+        assert_eq!(edge_count, 3 * pts.len() - 4 - 3);
+    }
+
+    #[test]
+    fn check_triangulate_is_locally_delaunay() {
+        // A larger, non-trivial point set: every triangle's opposite
+        // vertices must fail `in_circle` against each other, i.e. no
+        // edge flip would improve the mesh.
+        let m: Manifold<(f64, f64), ()> = Manifold::default();
+        let pts = [
+            (0.0, 0.0),
+            (2.0, 0.0),
+            (4.0, 0.0),
+            (1.0, 1.5),
+            (3.0, 1.5),
+            (0.5, 3.0),
+            (2.5, 3.0),
+            (4.5, 2.0),
+        ];
+        let e = m.triangulate(&pts).unwrap();
+
+        let mut seen = std::collections::HashSet::new();
+        let mut stack = vec![e];
+        let key = |n: Node<'_, (f64, f64), ()>| {
+            let (ox, oy) = *n.org_data().unwrap();
+            let (dx, dy) = *n.sym().org_data().unwrap();
+            (ox.to_bits(), oy.to_bits(), dx.to_bits(), dy.to_bits())
+        };
+        while let Some(n) = stack.pop() {
+            if !seen.insert(key(n)) {
+                continue;
+            }
+            // For every edge `n` whose left face and `n.sym()`'s left face
+            // are both real triangles, the far vertex of one must not lie
+            // inside the circumcircle of the other.
+            if n.face_ring().count() == 3 && n.sym().face_ring().count() == 3 {
+                let (a, b, c) = (org(n), dest(n), dest(n.lnext()));
+                let d = dest(n.sym().lnext());
+                assert!(in_circle(a, b, c, d) <= 0.0);
+            }
+            stack.push(n.onext());
+            stack.push(n.oprev());
+            stack.push(n.sym());
+        }
+    }
+}
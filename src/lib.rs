@@ -1,27 +1,60 @@
 #![allow(dead_code)]
-use std::{
+#![cfg_attr(not(feature = "std"), no_std)]
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+mod delaunay;
+pub use delaunay::{ccw, in_circle};
+
+use core::{
     cell::{Cell, RefCell},
+    fmt, ptr,
+};
+#[cfg(feature = "std")]
+use std::{
     collections::HashMap,
-    fmt,
     io::{self, BufRead, Read, Write},
-    ptr,
 };
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 use typed_arena::Arena;
 
 type VertCell<'m, V> = Cell<Option<&'m V>>;
 type FaceCell<'m, F> = Cell<Option<&'m F>>;
 
-#[derive(Copy, Clone, PartialEq, Debug)]
+// Manually implemented rather than derived: `derive` would add spurious
+// `V: Trait`/`F: Trait` bounds even though they only ever appear behind
+// the `&'m QuadEdge` reference, which already has its own hand-rolled
+// impls below for the same reason.
 pub struct Node<'m, V, F>(&'m QuadEdge<'m, V, F>, u8);
 
+impl<'m, V, F> Clone for Node<'m, V, F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'m, V, F> Copy for Node<'m, V, F> {}
+
+impl<'m, V, F> PartialEq for Node<'m, V, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0 && self.1 == other.1
+    }
+}
+
+impl<'m, V, F> fmt::Debug for Node<'m, V, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Node({:p}, {})", self.0, self.1)
+    }
+}
+
 impl<'m, V: Copy, F: Copy> Node<'m, V, F> {
     pub fn splice(self, other: Node<'m, V, F>) {
-        self.swap(other);
-        self.next().rot().swap(other.next().rot());
+        self.exchange_next(other);
+        self.next().rot().exchange_next(other.next().rot());
     }
 
     #[inline]
-    fn swap(self, other: Node<'m, V, F>) {
+    fn exchange_next(self, other: Node<'m, V, F>) {
         let self_next = self.next();
         self.set(other.next());
         other.set(self_next);
@@ -44,6 +77,169 @@ impl<'m, V: Copy, F: Copy> Node<'m, V, F> {
         let Node(q, i) = self;
         q.rot(i)
     }
+
+    /// The same edge, reversed: `org`/`dest` and `left`/`right` swap.
+    #[inline]
+    pub fn sym(self) -> Node<'m, V, F> {
+        self.rot().rot()
+    }
+
+    #[inline]
+    fn rot_inv(self) -> Node<'m, V, F> {
+        self.rot().rot().rot()
+    }
+
+    /// Next edge counterclockwise around `self`'s origin vertex.
+    #[inline]
+    pub fn onext(self) -> Node<'m, V, F> {
+        self.next()
+    }
+
+    /// Previous edge counterclockwise around `self`'s origin vertex.
+    #[inline]
+    pub fn oprev(self) -> Node<'m, V, F> {
+        self.rot().next().rot()
+    }
+
+    /// Next edge counterclockwise around `self`'s destination vertex.
+    #[inline]
+    pub fn dnext(self) -> Node<'m, V, F> {
+        self.sym().next().sym()
+    }
+
+    /// Previous edge counterclockwise around `self`'s destination vertex.
+    #[inline]
+    pub fn dprev(self) -> Node<'m, V, F> {
+        self.rot_inv().next().rot_inv()
+    }
+
+    /// Next edge counterclockwise around `self`'s left face.
+    #[inline]
+    pub fn lnext(self) -> Node<'m, V, F> {
+        self.rot_inv().next().rot()
+    }
+
+    /// Previous edge counterclockwise around `self`'s left face.
+    #[inline]
+    pub fn lprev(self) -> Node<'m, V, F> {
+        self.next().sym()
+    }
+
+    /// Next edge counterclockwise around `self`'s right face.
+    #[inline]
+    pub fn rnext(self) -> Node<'m, V, F> {
+        self.rot().next().rot_inv()
+    }
+
+    /// Previous edge counterclockwise around `self`'s right face.
+    #[inline]
+    pub fn rprev(self) -> Node<'m, V, F> {
+        self.sym().next()
+    }
+
+    /// Every edge leaving `self`'s origin vertex, starting with `self`.
+    pub fn vertex_ring(self) -> OrbitIter<'m, V, F> {
+        OrbitIter::new(self, Self::onext)
+    }
+
+    /// Every edge bounding `self`'s left face, starting with `self`.
+    pub fn face_ring(self) -> OrbitIter<'m, V, F> {
+        OrbitIter::new(self, Self::lnext)
+    }
+
+    /// Detaches `self` from its origin and destination vertex rings,
+    /// retiring it as a standalone edge before it's discarded.
+    pub fn delete_edge(self) {
+        self.splice(self.oprev());
+        self.sym().splice(self.sym().oprev());
+    }
+
+    /// Flips the diagonal of the quadrilateral formed by the two
+    /// triangles bordering `self`.
+    pub fn swap(self) {
+        let a = self.oprev();
+        let b = self.sym().oprev();
+        self.splice(a);
+        self.sym().splice(b);
+        self.splice(a.lnext());
+        self.sym().splice(b.lnext());
+    }
+
+    /// The vertex data attached to `self`'s origin, if any has been
+    /// attached while `self` was in that vertex ring (see
+    /// [`Manifold::set_org_data`]). An edge spliced into the ring after
+    /// the data was attached does not see it.
+    ///
+    /// Named `org_data` (not `org`) to stay distinct from
+    /// [`QuadEdge::orig`]'s topological node.
+    pub fn org_data(self) -> Option<&'m V> {
+        let Node(q, i) = self;
+        q.vert_cell(i).get()
+    }
+
+    /// The vertex data attached to `self`'s destination.
+    pub fn dest_data(self) -> Option<&'m V> {
+        self.sym().org_data()
+    }
+
+    /// The face data attached to `self`'s left face, if any has been
+    /// attached while `self` was in that face ring (see
+    /// [`Manifold::set_left_data`]). An edge spliced into the ring after
+    /// the data was attached does not see it.
+    pub fn left_data(self) -> Option<&'m F> {
+        let Node(q, i) = self.rot();
+        q.face_cell(i).get()
+    }
+
+    /// The face data attached to `self`'s right face.
+    pub fn right_data(self) -> Option<&'m F> {
+        self.sym().left_data()
+    }
+
+    #[inline]
+    fn set_vert(self, val: &'m V) {
+        let Node(q, i) = self;
+        q.vert_cell(i).set(Some(val));
+    }
+
+    #[inline]
+    fn set_face(self, val: &'m F) {
+        let Node(q, i) = self.rot();
+        q.face_cell(i).set(Some(val));
+    }
+}
+
+/// Iterates one orbit of a quad-edge mesh (a vertex ring or a face ring),
+/// following a fixed step function around until it returns to the start.
+pub struct OrbitIter<'m, V, F> {
+    start: Node<'m, V, F>,
+    current: Option<Node<'m, V, F>>,
+    step: fn(Node<'m, V, F>) -> Node<'m, V, F>,
+}
+
+impl<'m, V, F> OrbitIter<'m, V, F> {
+    fn new(start: Node<'m, V, F>, step: fn(Node<'m, V, F>) -> Node<'m, V, F>) -> Self {
+        OrbitIter {
+            start,
+            current: Some(start),
+            step,
+        }
+    }
+}
+
+impl<'m, V: Copy, F: Copy> Iterator for OrbitIter<'m, V, F> {
+    type Item = Node<'m, V, F>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.current?;
+        let stepped = (self.step)(node);
+        self.current = if stepped == self.start {
+            None
+        } else {
+            Some(stepped)
+        };
+        Some(node)
+    }
 }
 
 #[derive(Default)]
@@ -60,7 +256,7 @@ pub struct QuadEdge<'m, V, F> {
 impl<'m, V: Copy, F: Copy> QuadEdge<'m, V, F> {
     #[inline]
     pub fn orig(&'m self) -> Node<'m, V, F> {
-        self.ind(2)
+        self.ind(0)
     }
 
     #[inline]
@@ -72,6 +268,24 @@ impl<'m, V: Copy, F: Copy> QuadEdge<'m, V, F> {
         Node(self, i.rem_euclid(4))
     }
 
+    /// The vertex-data cell attached to the primal position `i` (0 or 2).
+    fn vert_cell(&'m self, i: u8) -> &'m VertCell<'m, V> {
+        match i.rem_euclid(4) {
+            0 => &self.data.0,
+            2 => &self.data.2,
+            i => panic!("vertex data only attaches to primal edge positions, got {i}"),
+        }
+    }
+
+    /// The face-data cell attached to the dual position `i` (1 or 3).
+    fn face_cell(&'m self, i: u8) -> &'m FaceCell<'m, F> {
+        match i.rem_euclid(4) {
+            1 => &self.data.1,
+            3 => &self.data.3,
+            i => panic!("face data only attaches to dual edge positions, got {i}"),
+        }
+    }
+
     #[inline]
     fn next(&'m self, i: u8) -> Node<'m, V, F> {
         self.next[i.rem_euclid(4) as usize]
@@ -110,6 +324,40 @@ impl<'m, V, F> fmt::Debug for QuadEdge<'m, V, F> {
     }
 }
 
+/// Writes `v` as a little-endian base-128 varint (LEB128, unsigned).
+#[cfg(feature = "std")]
+fn write_uvarint<W: Write>(buf: &mut W, mut v: u64) -> io::Result<()> {
+    loop {
+        let mut byte = (v & 0x7f) as u8;
+        v >>= 7;
+        if v != 0 {
+            byte |= 0x80;
+        }
+        buf.write_all(&[byte])?;
+        if v == 0 {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Reads a LEB128 unsigned varint written by [`write_uvarint`].
+#[cfg(feature = "std")]
+fn read_uvarint<R: Read>(buf: &mut R) -> io::Result<u64> {
+    let mut result = 0u64;
+    let mut shift = 0;
+    loop {
+        let mut byte = [0u8; 1];
+        buf.read_exact(&mut byte)?;
+        result |= ((byte[0] & 0x7f) as u64) << shift;
+        if byte[0] & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+    }
+    Ok(result)
+}
+
 #[derive(Default)]
 pub struct Manifold<'m, V, F> {
     quads: Arena<QuadEdge<'m, V, F>>,
@@ -126,6 +374,51 @@ impl<'m, V: Default + Copy, F: Default + Copy> Manifold<'m, V, F> {
         q
     }
 
+    /// Allocates a new standalone edge and returns its primal origin node.
+    pub fn make_edge(&'m self) -> Node<'m, V, F> {
+        self.make_quad().orig()
+    }
+
+    /// Creates a new edge `e` from `a`'s destination to `b`'s origin,
+    /// splicing it in so that it joins the rings of `a` and `b`.
+    pub fn connect(&'m self, a: Node<'m, V, F>, b: Node<'m, V, F>) -> Node<'m, V, F> {
+        let e = self.make_edge();
+        e.splice(a.lnext());
+        e.sym().splice(b);
+        e
+    }
+
+    /// Attaches `v` to `node`'s origin, allocating it in the vertex arena
+    /// and making it visible from every edge in `node`'s vertex ring, so
+    /// it can be read back from any of them via [`Node::org_data`].
+    pub fn set_org_data(&'m self, node: Node<'m, V, F>, v: V) {
+        let val = self.verts.alloc(v);
+        for n in node.vertex_ring() {
+            n.set_vert(val);
+        }
+    }
+
+    /// Attaches `v` to `node`'s destination; see [`Manifold::set_org_data`].
+    pub fn set_dest_data(&'m self, node: Node<'m, V, F>, v: V) {
+        self.set_org_data(node.sym(), v);
+    }
+
+    /// Attaches `v` to `node`'s left face, allocating it in the face arena
+    /// and making it visible from every edge bounding `node`'s left face,
+    /// so it can be read back from any of them via [`Node::left_data`].
+    pub fn set_left_data(&'m self, node: Node<'m, V, F>, v: F) {
+        let val = self.faces.alloc(v);
+        for n in node.face_ring() {
+            n.set_face(val);
+        }
+    }
+
+    /// Attaches `v` to `node`'s right face; see [`Manifold::set_left_data`].
+    pub fn set_right_data(&'m self, node: Node<'m, V, F>, v: F) {
+        self.set_left_data(node.sym(), v);
+    }
+
+    #[cfg(feature = "std")]
     pub fn export<W: Write>(&self, buf: &mut W) -> io::Result<()> {
         // Map the quad's address to its position.
         let map: HashMap<usize, usize> = self
@@ -156,6 +449,7 @@ impl<'m, V: Default + Copy, F: Default + Copy> Manifold<'m, V, F> {
         Ok(())
     }
 
+    #[cfg(feature = "std")]
     pub fn import<R: Read>(&'m self, buf: R) -> Result<(), Error> {
         let mut rel = Vec::new();
         let buf = io::BufReader::new(buf);
@@ -175,8 +469,78 @@ impl<'m, V: Default + Copy, F: Default + Copy> Manifold<'m, V, F> {
         }
         Ok(())
     }
+
+    /// Compact binary counterpart to [`Manifold::export`]: an 8-byte
+    /// little-endian quad count header, followed by one record per quad
+    /// (a single byte packing the four 2-bit sub-edge selectors, then
+    /// four LEB128 varints for the relative quad indices). Several times
+    /// smaller than the JSON-lines format and avoids per-line parsing.
+    #[cfg(feature = "std")]
+    pub fn export_binary<W: Write>(&self, buf: &mut W) -> io::Result<()> {
+        let map: HashMap<usize, usize> = self
+            .qrefs
+            .borrow()
+            .iter()
+            .enumerate()
+            .map(|(ind, &q)| (q as *const _ as usize, ind))
+            .collect();
+
+        let quads = self.qrefs.borrow();
+        buf.write_all(&(quads.len() as u64).to_le_bytes())?;
+        for &q in quads.iter() {
+            let refs: [(usize, u8); 4] = [0usize, 1, 2, 3].map(|k| {
+                let Node(qn, i) = q.next[k].get().expect("Node not initialized");
+                (map[&(qn as *const _ as usize)], i)
+            });
+            let sel = refs[0].1 | (refs[1].1 << 2) | (refs[2].1 << 4) | (refs[3].1 << 6);
+            buf.write_all(&[sel])?;
+            for &(r, _) in &refs {
+                write_uvarint(buf, r as u64)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Imports the binary format written by [`Manifold::export_binary`].
+    /// The quad count from the header lets us pre-allocate the arena and
+    /// `qrefs` before the second relink pass, mirroring the two-phase
+    /// approach in [`Manifold::import`].
+    #[cfg(feature = "std")]
+    pub fn import_binary<R: Read>(&'m self, mut buf: R) -> Result<(), Error> {
+        let mut count_bytes = [0u8; 8];
+        buf.read_exact(&mut count_bytes).map_err(Error::IO)?;
+        let count = u64::from_le_bytes(count_bytes) as usize;
+
+        self.quads.reserve_extend(count);
+        self.qrefs.borrow_mut().reserve(count);
+
+        let mut rel = Vec::with_capacity(count);
+        for _ in 0..count {
+            let mut sel = [0u8; 1];
+            buf.read_exact(&mut sel).map_err(Error::IO)?;
+            let sel = sel[0];
+            let mut refs = [(0usize, 0u8); 4];
+            for (k, slot) in refs.iter_mut().enumerate() {
+                let r = read_uvarint(&mut buf).map_err(Error::IO)? as usize;
+                let i = (sel >> (k * 2)) & 0b11;
+                *slot = (r, i);
+            }
+            let q = self.make_quad();
+            rel.push((q, refs));
+        }
+        for &(q, rn) in rel.iter() {
+            let [(i0, r0), (i1, r1), (i2, r2), (i3, r3)] = rn;
+            let n0 = Node(rel[i0].0, r0);
+            let n1 = Node(rel[i1].0, r1);
+            let n2 = Node(rel[i2].0, r2);
+            let n3 = Node(rel[i3].0, r3);
+            q.set_all(&[n0, n1, n2, n3]);
+        }
+        Ok(())
+    }
 }
 
+#[cfg(feature = "std")]
 #[derive(Debug)]
 pub enum Error {
     Serde(serde_json::Error),
@@ -186,7 +550,10 @@ pub enum Error {
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "std")]
     use stringreader::StringReader;
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
 
     #[test]
     fn check_u8_rem_euclid_4_aka_mod() {
@@ -246,15 +613,15 @@ mod tests {
     }
 
     #[test]
-    fn check_node_swap() {
+    fn check_node_exchange_next() {
         let q: QuadEdge<(), ()> = QuadEdge::default();
         q.set_all(&[Node(&q, 0), Node(&q, 3), Node(&q, 2), Node(&q, 1)]);
         assert_eq!(q.ind(0).next(), Node(&q, 0));
         assert_eq!(q.ind(1).next(), Node(&q, 3));
         assert_eq!(q.ind(2).next(), Node(&q, 2));
         assert_eq!(q.ind(3).next(), Node(&q, 1));
-        q.ind(0).swap(q.ind(2));
-        q.ind(1).swap(q.ind(3));
+        q.ind(0).exchange_next(q.ind(2));
+        q.ind(1).exchange_next(q.ind(3));
         assert_eq!(q.ind(0).next(), Node(&q, 2));
         assert_eq!(q.ind(1).next(), Node(&q, 1));
         assert_eq!(q.ind(2).next(), Node(&q, 0));
@@ -328,6 +695,7 @@ mod tests {
         assert_eq!(q.ind(3).next(), Node(q, 1));
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn check_manifold_export() {
         let m: Manifold<(), ()> = Manifold::default();
@@ -343,6 +711,7 @@ mod tests {
         assert_eq!(result, expected.to_string());
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn check_manifold_import() {
         let m: Manifold<(), ()> = Manifold::default();
@@ -361,4 +730,215 @@ mod tests {
         assert_eq!(q1.ind(2).next(), Node(q0, 2));
         assert_eq!(q1.ind(3).next(), Node(q1, 3));
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn check_uvarint_roundtrip() {
+        for v in [0u64, 1, 127, 128, 300, 16384, u64::MAX] {
+            let mut buf = Vec::new();
+            write_uvarint(&mut buf, v).expect("write_uvarint failed!");
+            let mut cursor = &buf[..];
+            assert_eq!(read_uvarint(&mut cursor).expect("read_uvarint failed!"), v);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn check_manifold_export_binary() {
+        let m: Manifold<(), ()> = Manifold::default();
+        let q0 = m.make_quad();
+        let q1 = m.make_quad();
+        q0.set_all(&[Node(q1, 3), Node(q0, 2), Node(q1, 1), Node(q0, 0)]);
+        q1.set_all(&[Node(q0, 0), Node(q1, 1), Node(q0, 2), Node(q1, 3)]);
+        let mut buf = Vec::new();
+        m.export_binary(&mut buf).expect("export_binary failed!");
+
+        let m2: Manifold<(), ()> = Manifold::default();
+        m2.import_binary(&buf[..]).expect("import_binary failed!");
+        assert_eq!(m2.quads.len(), 2);
+        let r0 = m2.qrefs.borrow()[0];
+        let r1 = m2.qrefs.borrow()[1];
+        assert_eq!(r0.ind(0).next(), Node(r1, 3));
+        assert_eq!(r0.ind(1).next(), Node(r0, 2));
+        assert_eq!(r0.ind(2).next(), Node(r1, 1));
+        assert_eq!(r0.ind(3).next(), Node(r0, 0));
+        assert_eq!(r1.ind(0).next(), Node(r0, 0));
+        assert_eq!(r1.ind(1).next(), Node(r1, 1));
+        assert_eq!(r1.ind(2).next(), Node(r0, 2));
+        assert_eq!(r1.ind(3).next(), Node(r1, 3));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn check_manifold_import_binary() {
+        let m: Manifold<(), ()> = Manifold::default();
+        let s = "[[1, 3],[0,2],[1,1 ],[0,0]]\n[[ 0,0],[1,1],[0,2],[1,3] ]";
+        let strrdr = StringReader::new(s);
+        m.import(strrdr).expect("Import from buffer failed!");
+
+        let mut buf = Vec::new();
+        m.export_binary(&mut buf).expect("export_binary failed!");
+
+        let m2: Manifold<(), ()> = Manifold::default();
+        m2.import_binary(&buf[..]).expect("import_binary failed!");
+        assert_eq!(m2.quads.len(), m.quads.len());
+
+        let mut a = Vec::new();
+        let mut b = Vec::new();
+        m.export(&mut a).expect("export failed!");
+        m2.export(&mut b).expect("export failed!");
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn check_node_sym() {
+        let q: QuadEdge<(), ()> = QuadEdge::default();
+        assert_eq!(q.ind(0).sym(), Node(&q, 2));
+        assert_eq!(q.ind(1).sym(), Node(&q, 3));
+        assert_eq!(q.ind(2).sym(), Node(&q, 0));
+        assert_eq!(q.ind(3).sym(), Node(&q, 1));
+    }
+
+    #[test]
+    fn check_node_orbit_ops_single_quad() {
+        let m: Manifold<(), ()> = Manifold::default();
+        let q = m.make_quad();
+        // A freshly made quad is a single standalone edge: its origin and
+        // destination vertex rings each contain just that one edge, while
+        // its left and right face rings each contain the edge and its sym.
+        assert_eq!(q.ind(0).onext(), q.ind(0));
+        assert_eq!(q.ind(0).oprev(), q.ind(0));
+        assert_eq!(q.ind(0).dnext(), q.ind(0));
+        assert_eq!(q.ind(0).dprev(), q.ind(0));
+        assert_eq!(q.ind(0).lnext(), q.ind(0).sym());
+        assert_eq!(q.ind(0).lprev(), q.ind(0).sym());
+        assert_eq!(q.ind(0).rnext(), q.ind(0).sym());
+        assert_eq!(q.ind(0).rprev(), q.ind(0).sym());
+    }
+
+    #[test]
+    fn check_orbit_iter_vertex_ring_single_node() {
+        let m: Manifold<(), ()> = Manifold::default();
+        let q = m.make_quad();
+        let ring: Vec<_> = q.ind(0).vertex_ring().collect();
+        assert_eq!(ring, vec![q.ind(0)]);
+    }
+
+    #[test]
+    fn check_orbit_iter_vertex_ring_two_quads() {
+        let m: Manifold<(), ()> = Manifold::default();
+        let q0 = m.make_quad();
+        let q1 = m.make_quad();
+        q0.ind(0).splice(q1.ind(0));
+        let ring: Vec<_> = q0.ind(0).vertex_ring().collect();
+        assert_eq!(ring, vec![q0.ind(0), q1.ind(0)]);
+    }
+
+    #[test]
+    fn check_orbit_iter_face_ring_single_quad() {
+        let m: Manifold<(), ()> = Manifold::default();
+        let q = m.make_quad();
+        // A lone edge bounds the (single, unbounded) face on both sides,
+        // so its face ring alternates between the edge and its sym.
+        let ring: Vec<_> = q.ind(0).face_ring().collect();
+        assert_eq!(ring, vec![q.ind(0), q.ind(0).sym()]);
+    }
+
+    #[test]
+    fn check_quadedge_orig_dest_distinct() {
+        let q: QuadEdge<(), ()> = QuadEdge::default();
+        assert_eq!(q.orig(), q.ind(0));
+        assert_eq!(q.dest(), q.ind(2));
+    }
+
+    #[test]
+    fn check_manifold_make_edge_is_standalone() {
+        let m: Manifold<(), ()> = Manifold::default();
+        let e = m.make_edge();
+        assert_eq!(e.vertex_ring().count(), 1);
+        assert_eq!(e.face_ring().count(), 2);
+    }
+
+    #[test]
+    fn check_manifold_connect_closes_triangle() {
+        let m: Manifold<(), ()> = Manifold::default();
+        let a = m.make_edge();
+        let b = m.make_edge();
+        a.sym().splice(b);
+        let c = m.connect(b, a);
+        let ring: Vec<_> = a.face_ring().collect();
+        assert_eq!(ring, vec![a, b, c]);
+    }
+
+    #[test]
+    fn check_node_delete_edge_reopens_face() {
+        let m: Manifold<(), ()> = Manifold::default();
+        let a = m.make_edge();
+        let b = m.make_edge();
+        a.sym().splice(b);
+        let c = m.connect(b, a);
+        assert_eq!(a.face_ring().count(), 3);
+        c.delete_edge();
+        assert_eq!(a.face_ring().count(), 4);
+    }
+
+    #[test]
+    fn check_node_swap_preserves_triangle_faces() {
+        let m: Manifold<(), ()> = Manifold::default();
+        // Triangle 1: a, b, c.
+        let a = m.make_edge();
+        let b = m.make_edge();
+        a.sym().splice(b);
+        m.connect(b, a);
+        // Triangle 2, glued onto `a`'s other side: d, g.
+        let d = m.make_edge();
+        a.oprev().splice(d);
+        m.connect(d, a.sym());
+        assert_eq!(a.face_ring().count(), 3);
+        assert_eq!(a.sym().face_ring().count(), 3);
+
+        // Flipping the shared diagonal keeps both sides triangular.
+        a.swap();
+        assert_eq!(a.face_ring().count(), 3);
+        assert_eq!(a.sym().face_ring().count(), 3);
+    }
+
+    #[test]
+    fn check_manifold_set_org_and_dest() {
+        let m: Manifold<i32, ()> = Manifold::default();
+        let a = m.make_edge();
+        m.set_org_data(a, 1);
+        m.set_dest_data(a, 2);
+        assert_eq!(a.org_data(), Some(&1));
+        assert_eq!(a.dest_data(), Some(&2));
+        assert_eq!(a.sym().org_data(), Some(&2));
+        assert_eq!(a.sym().dest_data(), Some(&1));
+    }
+
+    #[test]
+    fn check_manifold_set_org_visible_from_whole_vertex_ring() {
+        let m: Manifold<i32, ()> = Manifold::default();
+        let a = m.make_edge();
+        let b = m.make_edge();
+        a.sym().splice(b);
+        m.set_org_data(b, 7);
+        assert_eq!(a.sym().org_data(), Some(&7));
+        assert_eq!(b.org_data(), Some(&7));
+    }
+
+    #[test]
+    fn check_manifold_set_left_and_right() {
+        let m: Manifold<(), i32> = Manifold::default();
+        let a = m.make_edge();
+        let b = m.make_edge();
+        a.sym().splice(b);
+        let c = m.connect(b, a);
+        m.set_left_data(a, 10);
+        m.set_right_data(a, 20);
+        assert_eq!(a.left_data(), Some(&10));
+        assert_eq!(b.left_data(), Some(&10));
+        assert_eq!(c.left_data(), Some(&10));
+        assert_eq!(a.right_data(), Some(&20));
+        assert_eq!(a.sym().left_data(), Some(&20));
+    }
 }